@@ -5,13 +5,19 @@
 use bevy_macro_utils::BevyManifest;
 use proc_macro::TokenStream;
 use quote::{format_ident, quote};
-use syn::{parse_macro_input, Data, DeriveInput, Fields, Index, Path};
+use syn::{parse_macro_input, Data, DeriveInput, Expr, Fields, Index, Path};
 
 extern crate proc_macro;
 
-#[proc_macro_derive(Construct, attributes(prop))]
+#[proc_macro_derive(Construct, attributes(prop, construct))]
 pub fn derive_construct(input: TokenStream) -> TokenStream {
     let ast = parse_macro_input!(input as DeriveInput);
+    derive_construct_impl(ast)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+fn derive_construct_impl(ast: DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
     let manifest = BevyManifest::default();
     let ecs_path = manifest.get_path("bevy_ecs");
     let construct_path = manifest.get_path("bevy_construct_prototype");
@@ -20,19 +26,22 @@ pub fn derive_construct(input: TokenStream) -> TokenStream {
     let (impl_generics, type_generics, where_clause) = &ast.generics.split_for_impl();
 
     let props_type = format_ident!("{struct_name}Props");
+    let serde_derive = construct_serde_attr(&ast.attrs)?;
 
     let tokens = match &ast.data {
         Data::Struct(data_struct) => {
+            reject_prop_attr(&ast.attrs)?;
             let StructImpl {
                 is_named,
                 from_props_fields,
                 props_fields,
                 props_fields_defaults,
-            } = struct_impl(&data_struct.fields, &construct_path, false);
+            } = struct_impl(&data_struct.fields, &construct_path, false)?;
             let props_type_declaration = if is_named {
                 quote! {
                     #[allow(missing_docs)]
                     #[derive(Clone, Reflect)]
+                    #serde_derive
                     pub struct #props_type #impl_generics #where_clause {
                         #(#props_fields)*
                     }
@@ -49,6 +58,7 @@ pub fn derive_construct(input: TokenStream) -> TokenStream {
                 quote! {
                     #[allow(missing_docs)]
                     #[derive(Clone, Reflect)]
+                    #serde_derive
                     pub struct #props_type #impl_generics (#(#props_fields)*) #where_clause;
 
                     impl #impl_generics Default for #props_type #type_generics #where_clause {
@@ -78,31 +88,47 @@ pub fn derive_construct(input: TokenStream) -> TokenStream {
         Data::Enum(data_enum) => {
             let mut variant_props_entries = Vec::new();
             let mut variant_from_props_match = Vec::new();
-            let mut variant_apply_props = Vec::new();
 
-            let mut first_variant_default_ident = None;
+            // Props defaults to whichever variant is marked `#[construct(default)]`,
+            // falling back to the first variant with all its props defaulted.
+            let mut explicit_default_variant: Option<proc_macro2::TokenStream> = None;
+            let mut first_default_variant: Option<proc_macro2::TokenStream> = None;
+
             for variant in &data_enum.variants {
+                reject_prop_attr(&variant.attrs)?;
                 let StructImpl {
                     is_named,
                     from_props_fields,
                     props_fields,
-                    ..
-                } = struct_impl(&variant.fields, &construct_path, true);
+                    props_fields_defaults,
+                } = struct_impl(&variant.fields, &construct_path, true)?;
                 let ident = &variant.ident;
-                // Props will always default to the first variant with all None
-                let variant_name_lower = variant.ident.to_string().to_lowercase();
-                let variant_default_name = format_ident!("default_{}", variant_name_lower);
-                if first_variant_default_ident.is_none() {
-                    first_variant_default_ident = Some(variant_default_name.clone());
+
+                let default_expr = if variant.fields.is_empty() {
+                    quote! { #props_type::#ident }
+                } else if is_named {
+                    quote! { #props_type::#ident { #(#props_fields_defaults)* } }
+                } else {
+                    quote! { #props_type::#ident(#(#props_fields_defaults)*) }
+                };
+                if first_default_variant.is_none() {
+                    first_default_variant = Some(default_expr.clone());
+                }
+                if is_default_variant(&variant.attrs)? {
+                    if explicit_default_variant.is_some() {
+                        return Err(syn::Error::new_spanned(
+                            variant,
+                            "only one variant may be marked `#[construct(default)]`",
+                        ));
+                    }
+                    explicit_default_variant = Some(default_expr);
                 }
+
                 if variant.fields.is_empty() {
                     variant_props_entries.push(quote! {#ident});
                     variant_from_props_match.push(quote! {
                         #props_type::#ident => #struct_name::#ident,
                     });
-                    variant_apply_props.push(quote! {
-                        #props_type::#ident => {},
-                    });
                 } else {
                     let destructure_fields =
                         variant.fields.iter().enumerate().map(|(i, f)| {
@@ -124,13 +150,22 @@ pub fn derive_construct(input: TokenStream) -> TokenStream {
                 }
             }
 
+            let default_variant = explicit_default_variant.or(first_default_variant);
+
             quote! {
                 #[allow(missing_docs)]
                 #[derive(Clone, Reflect)]
-                pub enum #props_type #type_generics #where_clause {
+                #serde_derive
+                pub enum #props_type #impl_generics #where_clause {
                     #(#variant_props_entries,)*
                 }
 
+                impl #impl_generics Default for #props_type #type_generics #where_clause {
+                    fn default() -> Self {
+                        #default_variant
+                    }
+                }
+
                 impl #impl_generics #construct_path::Construct for #struct_name #type_generics #where_clause {
                     type Props = #props_type #type_generics #where_clause;
 
@@ -145,10 +180,15 @@ pub fn derive_construct(input: TokenStream) -> TokenStream {
                 }
             }
         }
-        Data::Union(_) => todo!("Union types are not supported yet."),
+        Data::Union(data_union) => {
+            return Err(syn::Error::new_spanned(
+                data_union.union_token,
+                "Construct cannot be derived for unions",
+            ));
+        }
     };
 
-    TokenStream::from(tokens)
+    Ok(tokens)
 }
 
 struct StructImpl {
@@ -160,7 +200,85 @@ struct StructImpl {
 
 const PROP: &str = "prop";
 
-fn struct_impl(fields: &Fields, construct_path: &Path, is_enum: bool) -> StructImpl {
+/// Errors if `attrs` contains a `#[prop]` attribute, for use on struct/variant
+/// definitions (with or without fields): `#[prop]` only applies to fields.
+fn reject_prop_attr(attrs: &[syn::Attribute]) -> syn::Result<()> {
+    if let Some(attr) = attrs.iter().find(|a| a.path().is_ident(PROP)) {
+        return Err(syn::Error::new_spanned(
+            attr,
+            "`#[prop]` can only be used on fields, not on a struct or variant",
+        ));
+    }
+    Ok(())
+}
+
+/// Parses a `#[prop]` attribute, returning the expression passed to a
+/// `default = ...` key, if any. Errors on any other key.
+fn parse_prop_attr(attr: &syn::Attribute) -> syn::Result<Option<Expr>> {
+    match &attr.meta {
+        syn::Meta::Path(_) => Ok(None),
+        syn::Meta::List(_) => {
+            let mut default = None;
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("default") {
+                    default = Some(meta.value()?.parse::<Expr>()?);
+                    Ok(())
+                } else {
+                    Err(meta.error("unknown key in `#[prop(...)]`, expected `default`"))
+                }
+            })?;
+            Ok(default)
+        }
+        syn::Meta::NameValue(name_value) => Err(syn::Error::new_spanned(
+            name_value,
+            "unknown keys in `#[prop = ...]`, expected a bare `#[prop]` or `#[prop(default = ...)]`",
+        )),
+    }
+}
+
+/// Reads a type-level `#[construct(serde)]` opt-in and returns the
+/// `derive(Serialize, Deserialize)` to splice onto the generated `Props`
+/// type, feature-gated so downstream crates that don't enable `serialize`
+/// don't pick up an unconditional `serde` dependency.
+fn construct_serde_attr(attrs: &[syn::Attribute]) -> syn::Result<proc_macro2::TokenStream> {
+    let Some(attr) = attrs.iter().find(|a| a.path().is_ident("construct")) else {
+        return Ok(quote!());
+    };
+    let mut serde = false;
+    attr.parse_nested_meta(|meta| {
+        if meta.path.is_ident("serde") {
+            serde = true;
+            Ok(())
+        } else {
+            Err(meta.error("unknown key in `#[construct(...)]`, expected `serde`"))
+        }
+    })?;
+    Ok(if serde {
+        quote! { #[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))] }
+    } else {
+        quote!()
+    })
+}
+
+/// Returns whether an enum variant is marked `#[construct(default)]`,
+/// erroring on any other key inside `#[construct(...)]`.
+fn is_default_variant(attrs: &[syn::Attribute]) -> syn::Result<bool> {
+    let Some(attr) = attrs.iter().find(|a| a.path().is_ident("construct")) else {
+        return Ok(false);
+    };
+    let mut is_default = false;
+    attr.parse_nested_meta(|meta| {
+        if meta.path.is_ident("default") {
+            is_default = true;
+            Ok(())
+        } else {
+            Err(meta.error("unknown key in `#[construct(...)]`, expected `default`"))
+        }
+    })?;
+    Ok(is_default)
+}
+
+fn struct_impl(fields: &Fields, construct_path: &Path, is_enum: bool) -> syn::Result<StructImpl> {
     let mut from_props_fields = Vec::new();
     let mut props_fields = Vec::new();
     let mut props_fields_defaults = Vec::new();
@@ -169,11 +287,9 @@ fn struct_impl(fields: &Fields, construct_path: &Path, is_enum: bool) -> StructI
         let ident = &field.ident;
         let ty = &field.ty;
         let field_index = Index::from(index);
-        let is_prop = field
-            .attrs
-            .iter()
-            .find(|a| a.path().is_ident(PROP))
-            .is_some();
+        let prop_attr = field.attrs.iter().find(|a| a.path().is_ident(PROP));
+        let default_expr = prop_attr.map(parse_prop_attr).transpose()?.flatten();
+        let is_prop = prop_attr.is_some();
         let is_pub = matches!(field.vis, syn::Visibility::Public(_));
         let maybe_pub = if is_pub { quote!(pub) } else { quote!() };
         if is_named {
@@ -181,8 +297,12 @@ fn struct_impl(fields: &Fields, construct_path: &Path, is_enum: bool) -> StructI
                 props_fields.push(quote! {
                     #maybe_pub #ident: #construct_path::ConstructProp<#ty>,
                 });
+                let props_default = default_expr.as_ref().map_or_else(
+                    || quote! { #construct_path::ConstructProp::Props(Default::default()) },
+                    |expr| quote! { #construct_path::ConstructProp::Props(#expr) },
+                );
                 props_fields_defaults.push(quote! {
-                    #ident: #construct_path::ConstructProp::Props(Default::default()),
+                    #ident: #props_default,
                 });
 
                 if is_enum {
@@ -204,8 +324,11 @@ fn struct_impl(fields: &Fields, construct_path: &Path, is_enum: bool) -> StructI
                 props_fields.push(quote! {
                     #maybe_pub #ident: #ty,
                 });
+                let field_default = default_expr
+                    .as_ref()
+                    .map_or_else(|| quote! { Default::default() }, |expr| quote! { #expr });
                 props_fields_defaults.push(quote! {
-                    #ident: Default::default(),
+                    #ident: #field_default,
                 });
 
                 if is_enum {
@@ -224,8 +347,12 @@ fn struct_impl(fields: &Fields, construct_path: &Path, is_enum: bool) -> StructI
                     #maybe_pub #construct_path::ConstructProp<#ty>,
                 });
 
+                let props_default = default_expr.as_ref().map_or_else(
+                    || quote! { #construct_path::ConstructProp::Props(Default::default()) },
+                    |expr| quote! { #construct_path::ConstructProp::Props(#expr) },
+                );
                 props_fields_defaults.push(quote! {
-                    #construct_path::ConstructProp::Props(Default::default()),
+                    #props_default,
                 });
 
                 if is_enum {
@@ -253,8 +380,11 @@ fn struct_impl(fields: &Fields, construct_path: &Path, is_enum: bool) -> StructI
                     #maybe_pub #ty,
                 });
 
+                let field_default = default_expr
+                    .as_ref()
+                    .map_or_else(|| quote! { Default::default() }, |expr| quote! { #expr });
                 props_fields_defaults.push(quote! {
-                    Default::default(),
+                    #field_default,
                 });
 
                 if is_enum {
@@ -267,10 +397,73 @@ fn struct_impl(fields: &Fields, construct_path: &Path, is_enum: bool) -> StructI
         }
     }
 
-    StructImpl {
+    Ok(StructImpl {
         is_named,
         from_props_fields,
         props_fields,
         props_fields_defaults,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::derive_construct_impl;
+
+    fn derive_err(input: &str) -> String {
+        let ast = syn::parse_str(input).expect("test input must itself be valid Rust");
+        derive_construct_impl(ast)
+            .expect_err("expected derive_construct_impl to return an error")
+            .to_string()
+    }
+
+    #[test]
+    fn unions_are_rejected_with_a_spanned_error() {
+        let message = derive_err("union Foo { a: u32, b: f32 }");
+        assert_eq!(message, "Construct cannot be derived for unions");
+    }
+
+    #[test]
+    fn prop_attr_on_a_unit_struct_is_rejected() {
+        let message = derive_err("#[prop] struct Foo;");
+        assert_eq!(
+            message,
+            "`#[prop]` can only be used on fields, not on a struct or variant"
+        );
+    }
+
+    #[test]
+    fn prop_attr_on_a_unit_variant_is_rejected() {
+        let message = derive_err("enum Foo { #[prop] Bar }");
+        assert_eq!(
+            message,
+            "`#[prop]` can only be used on fields, not on a struct or variant"
+        );
+    }
+
+    #[test]
+    fn prop_attr_on_a_struct_with_fields_is_rejected() {
+        let message = derive_err("#[prop] struct Foo { x: i32 }");
+        assert_eq!(
+            message,
+            "`#[prop]` can only be used on fields, not on a struct or variant"
+        );
+    }
+
+    #[test]
+    fn unknown_key_in_prop_attr_is_rejected() {
+        let message = derive_err("struct Foo { #[prop(bogus = 1)] x: i32 }");
+        assert!(
+            message.contains("unknown key in `#[prop(...)]`"),
+            "unexpected error message: {message}"
+        );
+    }
+
+    #[test]
+    fn unknown_key_in_construct_attr_is_rejected() {
+        let message = derive_err("#[construct(bogus)] struct Foo { x: i32 }");
+        assert!(
+            message.contains("unknown key in `#[construct(...)]`"),
+            "unexpected error message: {message}"
+        );
     }
 }
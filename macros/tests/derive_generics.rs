@@ -0,0 +1,9 @@
+//! Compile-pass coverage for generic-parameter handling in `#[derive(Construct)]`:
+//! lifetime params and bounded params on structs, and where-clauses on both
+//! structs and enums.
+
+#[test]
+fn generics() {
+    let t = trybuild::TestCases::new();
+    t.pass("tests/pass/*.rs");
+}
@@ -0,0 +1,11 @@
+use bevy_construct_prototype_macros::Construct;
+use bevy_reflect::Reflect;
+use std::fmt::Debug;
+
+#[derive(Construct)]
+struct Wrapper<T: Clone + Default + Debug> {
+    #[prop]
+    value: T,
+}
+
+fn main() {}
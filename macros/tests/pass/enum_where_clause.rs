@@ -0,0 +1,14 @@
+use bevy_construct_prototype_macros::Construct;
+use bevy_reflect::Reflect;
+
+#[derive(Construct)]
+enum Either<L, R>
+where
+    L: Clone + Default,
+    R: Clone + Default,
+{
+    Left(#[prop] L),
+    Right(#[prop] R),
+}
+
+fn main() {}
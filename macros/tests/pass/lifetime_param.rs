@@ -0,0 +1,10 @@
+use bevy_construct_prototype_macros::Construct;
+use bevy_reflect::Reflect;
+
+#[derive(Construct)]
+struct Labeled<'a> {
+    #[prop]
+    name: &'a str,
+}
+
+fn main() {}
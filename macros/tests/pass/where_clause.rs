@@ -0,0 +1,16 @@
+use bevy_construct_prototype_macros::Construct;
+use bevy_reflect::Reflect;
+
+#[derive(Construct)]
+struct Pair<L, R>
+where
+    L: Clone + Default,
+    R: Clone + Default,
+{
+    #[prop]
+    left: L,
+    #[prop]
+    right: R,
+}
+
+fn main() {}
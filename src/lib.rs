@@ -8,7 +8,8 @@ use bevy_ecs::{
     world::error::EntityFetchError,
 };
 use bevy_ptr::OwningPtr;
-use bevy_reflect::{FromType, Reflect};
+use bevy_reflect::{FromType, Reflect, TypeRegistry};
+use std::any::TypeId;
 use std::borrow::Cow;
 use thiserror::Error;
 use variadics_please::all_tuples;
@@ -23,6 +24,8 @@ pub enum ConstructError {
     MissingResource { type_name: &'static str },
     #[error("Props were invalid: {message}")]
     InvalidProps { message: Cow<'static, str> },
+    #[error("{type_path} does not have `ReflectConstruct` registered")]
+    NotRegistered { type_path: &'static str },
 }
 
 pub trait Construct: Sized {
@@ -57,6 +60,33 @@ where
     }
 }
 
+/// Scene/prefab data only ever encodes the unconstructed `Props` side of a
+/// [`ConstructProp`], so deserializing always produces [`ConstructProp::Props`].
+#[cfg(feature = "serialize")]
+impl<C: Construct> serde::Serialize for ConstructProp<C>
+where
+    C::Props: serde::Serialize,
+{
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::Props(props) => props.serialize(serializer),
+            Self::Value(_) => Err(serde::ser::Error::custom(
+                "cannot serialize an already-constructed `ConstructProp::Value`",
+            )),
+        }
+    }
+}
+
+#[cfg(feature = "serialize")]
+impl<'de, C: Construct> serde::Deserialize<'de> for ConstructProp<C>
+where
+    C::Props: serde::Deserialize<'de>,
+{
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(Self::Props(C::Props::deserialize(deserializer)?))
+    }
+}
+
 #[derive(Clone)]
 pub struct ReflectConstruct {
     pub default_props: fn() -> Box<dyn Reflect>,
@@ -82,6 +112,30 @@ where
     }
 }
 
+/// Drives construction from reflected `props` (e.g. deserialized from a RON
+/// scene/prefab file) by looking up the [`ReflectConstruct`] registered for
+/// `type_id` in `registry` and calling its `construct` function. Nested
+/// `#[prop]` fields are handled automatically: the derive-generated
+/// `Construct::construct` already recurses into them, resolving each
+/// `ConstructProp::Props` or using the already-built `ConstructProp::Value` as-is.
+pub fn construct_reflected(
+    registry: &TypeRegistry,
+    type_id: TypeId,
+    props: Box<dyn Reflect>,
+    entity: &mut EntityWorldMut,
+) -> Result<Box<dyn Reflect>, ConstructError> {
+    let registration = registry.get(type_id);
+    let reflect_construct =
+        registration.and_then(|registration| registration.data::<ReflectConstruct>());
+    let Some(reflect_construct) = reflect_construct else {
+        let type_path = registration.map_or("<unknown type>", |registration| {
+            registration.type_info().type_path()
+        });
+        return Err(ConstructError::NotRegistered { type_path });
+    };
+    (reflect_construct.construct)(props, entity)
+}
+
 /// This exists because we cannot impl [`Construct`] for tuples, as that would conflict with the blanket impl of [`Construct`] for [`Default`].
 /// This isn't ideal, but given the choice between the nice UX of [`Default`] types being [`Construct`], or the internal Construct behavior of
 /// tuples being slightly weirder, we'll take the nice UX.
@@ -136,8 +190,8 @@ unsafe impl<B: Bundle> Bundle for ConstructTuple<B> {
         B::register_required_components(components, storages, required_components);
     }
 
-    fn get_component_ids(_components: &Components, _ids: &mut impl FnMut(Option<ComponentId>)) {
-        todo!("Not yet implemented for ConstructTuple")
+    fn get_component_ids(components: &Components, ids: &mut impl FnMut(Option<ComponentId>)) {
+        B::get_component_ids(components, ids);
     }
 }
 
@@ -0,0 +1,51 @@
+//! Coverage for driving construction from reflected `Props` data through the
+//! `ReflectConstruct` type registration, as described in
+//! `construct_reflected`'s doc comment.
+
+use bevy_construct_prototype::{
+    construct_reflected, Construct, ConstructError, ConstructProp, ReflectConstruct,
+};
+use bevy_ecs::prelude::{Component, World};
+use bevy_reflect::{Reflect, TypeRegistry};
+use std::any::TypeId;
+
+#[derive(Component, Reflect, Construct)]
+struct Health {
+    #[prop]
+    pub current: u32,
+}
+
+#[test]
+fn construct_reflected_drives_construction_for_a_registered_type() {
+    let mut registry = TypeRegistry::new();
+    registry.register::<Health>();
+    registry.register_type_data::<Health, ReflectConstruct>();
+
+    let mut world = World::new();
+    let mut entity = world.spawn_empty();
+
+    let props: Box<dyn Reflect> = Box::new(HealthProps {
+        current: ConstructProp::Props(7),
+    });
+    let constructed = construct_reflected(&registry, TypeId::of::<Health>(), props, &mut entity)
+        .expect("Health is registered for ReflectConstruct");
+
+    let health = constructed
+        .downcast::<Health>()
+        .ok()
+        .expect("constructed value downcasts to Health");
+    assert_eq!(health.current, 7);
+}
+
+#[test]
+fn construct_reflected_errors_when_the_type_is_not_registered() {
+    let registry = TypeRegistry::new();
+    let mut world = World::new();
+    let mut entity = world.spawn_empty();
+
+    let props: Box<dyn Reflect> = Box::new(HealthProps::default());
+    let err =
+        construct_reflected(&registry, TypeId::of::<Health>(), props, &mut entity).unwrap_err();
+
+    assert!(matches!(err, ConstructError::NotRegistered { .. }));
+}
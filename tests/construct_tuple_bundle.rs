@@ -0,0 +1,25 @@
+//! Coverage for `ConstructTuple`'s `Bundle::get_component_ids`, which forwards
+//! to the inner bundle so component-id enumeration (archetype diagnostics,
+//! bundle insertion fast paths, required-component resolution) doesn't panic
+//! on a constructed tuple.
+
+use bevy_construct_prototype::ConstructTuple;
+use bevy_ecs::prelude::{Bundle, Component, World};
+
+#[derive(Component)]
+struct Health {
+    current: u32,
+}
+
+#[test]
+fn get_component_ids_forwards_to_the_inner_bundle() {
+    let mut world = World::new();
+    let component_id = world.register_component::<Health>();
+
+    let mut collected = Vec::new();
+    <ConstructTuple<(Health,)> as Bundle>::get_component_ids(world.components(), &mut |id| {
+        collected.push(id);
+    });
+
+    assert_eq!(collected, vec![Some(component_id)]);
+}
@@ -0,0 +1,54 @@
+//! Coverage for `#[prop(default = ...)]` field defaults and `#[construct(default)]`
+//! enum variant selection.
+
+use bevy_construct_prototype::{Construct, ConstructProp};
+use bevy_reflect::Reflect;
+
+#[derive(Construct)]
+struct Health {
+    #[prop(default = 100)]
+    pub max: u32,
+    #[prop]
+    pub current: u32,
+}
+
+#[test]
+fn field_default_expression_is_used() {
+    let props = HealthProps::default();
+    match props.max {
+        ConstructProp::Props(max) => assert_eq!(max, 100),
+        ConstructProp::Value(_) => panic!("expected `ConstructProp::Props`"),
+    }
+}
+
+#[test]
+fn field_without_default_falls_back_to_default_default() {
+    let props = HealthProps::default();
+    match props.current {
+        ConstructProp::Props(current) => assert_eq!(current, 0),
+        ConstructProp::Value(_) => panic!("expected `ConstructProp::Props`"),
+    }
+}
+
+#[derive(Construct)]
+enum Mode {
+    Idle,
+    Active,
+}
+
+#[test]
+fn default_variant_falls_back_to_the_first_variant_when_unmarked() {
+    assert!(matches!(ModeProps::default(), ModeProps::Idle));
+}
+
+#[derive(Construct)]
+enum Status {
+    Alive,
+    #[construct(default)]
+    Dead,
+}
+
+#[test]
+fn explicit_default_variant_is_selected() {
+    assert!(matches!(StatusProps::default(), StatusProps::Dead));
+}
@@ -0,0 +1,28 @@
+//! Coverage for the `#[construct(serde)]` opt-in on generated `Props` types.
+
+#![cfg(feature = "serialize")]
+
+use bevy_construct_prototype::{Construct, ConstructProp};
+use bevy_reflect::Reflect;
+
+#[derive(Construct)]
+#[construct(serde)]
+struct Health {
+    #[prop]
+    pub current: u32,
+}
+
+#[test]
+fn props_serialize_deserialize_round_trips() {
+    let props = HealthProps {
+        current: ConstructProp::Props(42),
+    };
+
+    let json = serde_json::to_string(&props).expect("Props derives Serialize");
+    let parsed: HealthProps = serde_json::from_str(&json).expect("Props derives Deserialize");
+
+    match parsed.current {
+        ConstructProp::Props(current) => assert_eq!(current, 42),
+        ConstructProp::Value(_) => panic!("deserializing always produces `ConstructProp::Props`"),
+    }
+}